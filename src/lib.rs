@@ -19,19 +19,46 @@ mod test;
 /// Iterator that yields equally spaced numbers in the linear scale
 #[derive(Clone)]
 pub struct Linspace<T> where T: Floaty {
+    end: T,
+    len: usize,
     start: T,
     state: usize,
-    step: T,
     stop: usize,
 }
 
+impl<T> Linspace<T> where T: Floaty {
+    // Two-sided weighted interpolation: exact at both `i == 0` and `i == len - 1`
+    fn at(&self, i: usize) -> T {
+        if self.len < 2 {
+            // NB The interpolation weights are undefined in these cases
+            self.start
+        } else {
+            let n = self.len - 1;
+            self.start * (T::cast(n - i) / T::cast(n)) + self.end * (T::cast(i) / T::cast(n))
+        }
+    }
+}
+
 impl<T> DoubleEndedIterator for Linspace<T> where T: Floaty {
     fn next_back(&mut self) -> Option<T> {
         if self.state == self.stop {
             None
         } else {
             self.stop -= 1;
-            Some(self.start + self.step * T::cast(self.stop))
+            Some(self.at(self.stop))
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        match n.checked_add(1).and_then(|n| self.stop.checked_sub(n)) {
+            Some(stop) if stop >= self.state => {
+                self.stop = stop;
+                Some(self.at(stop))
+            }
+            _ => {
+                self.stop = self.state;
+                None
+            }
         }
     }
 }
@@ -43,34 +70,84 @@ impl<T> Iterator for Linspace<T> where T: Floaty {
         if self.state == self.stop {
             None
         } else {
-            let next = self.start + self.step * T::cast(self.state);
+            let next = self.at(self.state);
             self.state += 1;
             Some(next)
         }
     }
 
+    fn nth(&mut self, n: usize) -> Option<T> {
+        let state = self.state.saturating_add(n);
+
+        if state >= self.stop {
+            self.state = self.stop;
+            None
+        } else {
+            self.state = state + 1;
+            Some(self.at(state))
+        }
+    }
+
     fn size_hint(&self) -> (usize, Option<usize>) {
         let exact = self.stop - self.state;
         (exact, Some(exact))
     }
 }
 
+impl<T> ExactSizeIterator for Linspace<T> where T: Floaty {}
+
 /// Iterator that yields equally spaced numbers in the logarithmic scale
 #[derive(Clone)]
 pub struct Logspace<T> where T: Floaty {
+    end: T,
+    len: usize,
+    raw_end: T,
+    raw_start: T,
     start: T,
     state: usize,
-    step: T,
     stop: usize,
 }
 
+impl<T> Logspace<T> where T: Floaty {
+    // Two-sided weighted interpolation over the log-domain endpoints
+    fn at(&self, i: usize) -> T {
+        let n = self.len - 1;
+        self.start * (T::cast(n - i) / T::cast(n)) + self.end * (T::cast(i) / T::cast(n))
+    }
+
+    // `exp(ln(x))` isn't bit-exact for `x` in general, so the endpoints are returned directly
+    // instead of being routed back through the log domain
+    fn value(&self, i: usize) -> T {
+        if i == 0 {
+            self.raw_start
+        } else if i == self.len - 1 {
+            self.raw_end
+        } else {
+            self.at(i).exp()
+        }
+    }
+}
+
 impl<T> DoubleEndedIterator for Logspace<T> where T: Floaty {
     fn next_back(&mut self) -> Option<T> {
         if self.state == self.stop {
             None
         } else {
             self.stop -= 1;
-            Some((self.start + self.step * T::cast(self.stop)).exp())
+            Some(self.value(self.stop))
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        match n.checked_add(1).and_then(|n| self.stop.checked_sub(n)) {
+            Some(stop) if stop >= self.state => {
+                self.stop = stop;
+                Some(self.value(stop))
+            }
+            _ => {
+                self.stop = self.state;
+                None
+            }
         }
     }
 }
@@ -82,9 +159,192 @@ impl<T> Iterator for Logspace<T> where T: Floaty {
         if self.state == self.stop {
             None
         } else {
-            let next = self.start + self.step * T::cast(self.state);
+            let next = self.value(self.state);
+            self.state += 1;
+            Some(next)
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        let state = self.state.saturating_add(n);
+
+        if state >= self.stop {
+            self.state = self.stop;
+            None
+        } else {
+            self.state = state + 1;
+            Some(self.value(state))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let exact = self.stop - self.state;
+        (exact, Some(exact))
+    }
+}
+
+impl<T> ExactSizeIterator for Logspace<T> where T: Floaty {}
+
+/// Iterator that yields geometrically spaced numbers
+#[derive(Clone)]
+pub struct Geomspace<T> where T: Floaty {
+    end: T,
+    len: usize,
+    raw_end: T,
+    raw_start: T,
+    sign: T,
+    start: T,
+    state: usize,
+    stop: usize,
+}
+
+impl<T> Geomspace<T> where T: Floaty {
+    // Two-sided weighted interpolation over the log-domain endpoints
+    fn at(&self, i: usize) -> T {
+        let n = self.len - 1;
+        self.start * (T::cast(n - i) / T::cast(n)) + self.end * (T::cast(i) / T::cast(n))
+    }
+
+    // `exp(ln(x))` isn't bit-exact for `x` in general, so the endpoints are returned directly
+    // instead of being routed back through the log domain
+    fn value(&self, i: usize) -> T {
+        if i == 0 {
+            self.raw_start
+        } else if i == self.len - 1 {
+            self.raw_end
+        } else {
+            self.sign * self.at(i).exp()
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for Geomspace<T> where T: Floaty {
+    fn next_back(&mut self) -> Option<T> {
+        if self.state == self.stop {
+            None
+        } else {
+            self.stop -= 1;
+            Some(self.value(self.stop))
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        match n.checked_add(1).and_then(|n| self.stop.checked_sub(n)) {
+            Some(stop) if stop >= self.state => {
+                self.stop = stop;
+                Some(self.value(stop))
+            }
+            _ => {
+                self.stop = self.state;
+                None
+            }
+        }
+    }
+}
+
+impl<T> Iterator for Geomspace<T> where T: Floaty {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.state == self.stop {
+            None
+        } else {
+            let next = self.value(self.state);
+            self.state += 1;
+            Some(next)
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        let state = self.state.saturating_add(n);
+
+        if state >= self.stop {
+            self.state = self.stop;
+            None
+        } else {
+            self.state = state + 1;
+            Some(self.value(state))
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let exact = self.stop - self.state;
+        (exact, Some(exact))
+    }
+}
+
+impl<T> ExactSizeIterator for Geomspace<T> where T: Floaty {}
+
+/// Iterator that yields equally spaced numbers in the logarithmic scale, for an arbitrary base
+#[derive(Clone)]
+pub struct LogspaceBase<T> where T: Floaty {
+    base: T,
+    end: T,
+    len: usize,
+    start: T,
+    state: usize,
+    stop: usize,
+}
+
+impl<T> LogspaceBase<T> where T: Floaty {
+    // Two-sided weighted interpolation over the exponents
+    fn at(&self, i: usize) -> T {
+        if self.len < 2 {
+            // NB The interpolation weights are undefined in these cases
+            self.start
+        } else {
+            let n = self.len - 1;
+            self.start * (T::cast(n - i) / T::cast(n)) + self.end * (T::cast(i) / T::cast(n))
+        }
+    }
+}
+
+impl<T> DoubleEndedIterator for LogspaceBase<T> where T: Floaty {
+    fn next_back(&mut self) -> Option<T> {
+        if self.state == self.stop {
+            None
+        } else {
+            self.stop -= 1;
+            Some(self.base.powf(self.at(self.stop)))
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        match n.checked_add(1).and_then(|n| self.stop.checked_sub(n)) {
+            Some(stop) if stop >= self.state => {
+                self.stop = stop;
+                Some(self.base.powf(self.at(stop)))
+            }
+            _ => {
+                self.stop = self.state;
+                None
+            }
+        }
+    }
+}
+
+impl<T> Iterator for LogspaceBase<T> where T: Floaty {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.state == self.stop {
+            None
+        } else {
+            let next = self.at(self.state);
             self.state += 1;
-            Some(next.exp())
+            Some(self.base.powf(next))
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T> {
+        let state = self.state.saturating_add(n);
+
+        if state >= self.stop {
+            self.state = self.stop;
+            None
+        } else {
+            self.state = state + 1;
+            Some(self.base.powf(self.at(state)))
         }
     }
 
@@ -94,6 +354,55 @@ impl<T> Iterator for Logspace<T> where T: Floaty {
     }
 }
 
+impl<T> ExactSizeIterator for LogspaceBase<T> where T: Floaty {}
+
+/// Iterator that yields numbers in the `[start, end)` interval, separated by a constant `step`
+#[derive(Clone)]
+pub struct Range<T> where T: Floaty {
+    start: T,
+    state: usize,
+    step: T,
+    stop: usize,
+}
+
+impl<T> Range<T> where T: Floaty {
+    fn at(&self, i: usize) -> T {
+        self.start + self.step * T::cast(i)
+    }
+}
+
+impl<T> DoubleEndedIterator for Range<T> where T: Floaty {
+    fn next_back(&mut self) -> Option<T> {
+        if self.state == self.stop {
+            None
+        } else {
+            self.stop -= 1;
+            Some(self.at(self.stop))
+        }
+    }
+}
+
+impl<T> Iterator for Range<T> where T: Floaty {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.state == self.stop {
+            None
+        } else {
+            let next = self.at(self.state);
+            self.state += 1;
+            Some(next)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let exact = self.stop - self.state;
+        (exact, Some(exact))
+    }
+}
+
+impl<T> ExactSizeIterator for Range<T> where T: Floaty {}
+
 /// Returns an iterator that yields `n` evenly spaced numbers over the `[start, end]` interval
 ///
 /// # Panics
@@ -113,17 +422,11 @@ impl<T> Iterator for Logspace<T> where T: Floaty {
 pub fn linspace<T>(start: T, end: T, n: usize) -> Linspace<T> where T: Floaty {
     assert!(start <= end);
 
-    let step = if n < 2 {
-        // NB The value of `step` doesn't matter in these cases
-        T::cast(0)
-    } else {
-        (end - start) / T::cast(n - 1)
-    };
-
     Linspace {
+        end: end,
+        len: n,
         start: start,
         state: 0,
-        step: step,
         stop: n,
     }
 }
@@ -149,19 +452,136 @@ pub fn logspace<T>(start: T, end: T, n: usize) -> Logspace<T> where T: Floaty {
 
     assert!(start > _0 && end > _0 && start <= end);
 
+    let (raw_start, raw_end) = (start, end);
     let (start, end) = (start.ln(), end.ln());
 
-    let step = if n < 2 {
-        // NB The value of `step` doesn't matter in these cases
-        _0
+    Logspace {
+        end: end,
+        len: n,
+        raw_end: raw_end,
+        raw_start: raw_start,
+        start: start,
+        state: 0,
+        stop: n,
+    }
+}
+
+/// Geometric version of `linspace`
+///
+/// Unlike `logspace`, this also accepts an all-negative range, as long as `start` and `end` share
+/// a sign.
+///
+/// # Panics
+///
+/// Panics if `start` or `end` is zero, if they have different signs, or if `end` < `start`
+///
+/// # Examples
+///
+/// **Note** These assertions will likely fail because of rounding errors. (In real applications
+/// you shouldn't directly use equality between floats, but instead check that the absolute
+/// difference is within some tolerance)
+///
+/// ``` ignore
+/// assert_eq!(vec![0.1, 1., 10., 100.], geomspace(0.1, 100., 4).collect::<Vec<_>>())
+/// assert_eq!(vec![-1000., -100., -10., -1.], geomspace(-1000., -1., 4).collect::<Vec<_>>())
+/// ```
+pub fn geomspace<T>(start: T, end: T, n: usize) -> Geomspace<T> where T: Floaty {
+    let _0 = T::cast(0);
+
+    assert!(start != _0 && end != _0);
+    assert!(start.signum() == end.signum());
+    assert!(start <= end);
+
+    let (raw_start, raw_end) = (start, end);
+    let sign = start.signum();
+    let (start, end) = ((sign * start).ln(), (sign * end).ln());
+
+    Geomspace {
+        end: end,
+        len: n,
+        raw_end: raw_end,
+        raw_start: raw_start,
+        sign: sign,
+        start: start,
+        state: 0,
+        stop: n,
+    }
+}
+
+/// NumPy/ndarray-style version of `logspace` that interpolates exponents of an arbitrary `base`
+///
+/// Unlike `logspace`, `start` and `end` are exponents of `base` rather than raw values. This
+/// lets you pick a `base` other than `e` -- e.g. `10` for decade sweeps, `2` for octave sweeps --
+/// and removes the positivity constraint on the endpoints, since they are exponents.
+///
+/// # Panics
+///
+/// Panics if `end` < `start`
+///
+/// # Examples
+///
+/// **Note** These assertions will likely fail because of rounding errors. (In real applications
+/// you shouldn't directly use equality between floats, but instead check that the absolute
+/// difference is within some tolerance)
+///
+/// ``` ignore
+/// assert_eq!(vec![1., 10., 100., 1000.], logspace_base(10., 0., 3., 4).collect::<Vec<_>>())
+/// assert_eq!(vec![1000., 100., 10., 1.], logspace_base(10., 0., 3., 4).rev().collect::<Vec<_>>())
+/// ```
+pub fn logspace_base<T>(base: T, start: T, end: T, n: usize) -> LogspaceBase<T> where T: Floaty {
+    assert!(start <= end);
+
+    LogspaceBase {
+        base: base,
+        end: end,
+        len: n,
+        start: start,
+        state: 0,
+        stop: n,
+    }
+}
+
+/// Returns an iterator that yields numbers in the `[start, end)` interval, separated by `step`
+///
+/// Unlike `linspace`, which fixes the *count* of points, this fixes the *step* between them, like
+/// NumPy's `arange` or ndarray's `Array::range`
+///
+/// # Panics
+///
+/// Panics if `step` is zero, or if `step` and `end - start` have different signs
+///
+/// # Examples
+///
+/// ``` ignore
+/// assert_eq!(vec![0., 1., 2., 3.], range(0., 4., 1.).collect::<Vec<_>>())
+/// assert_eq!(vec![3., 2., 1., 0.], range(0., 4., 1.).rev().collect::<Vec<_>>())
+/// ```
+pub fn range<T>(start: T, end: T, step: T) -> Range<T> where T: Floaty, usize: ::cast::From<T> {
+    let _0 = T::cast(0);
+
+    assert!(step != _0);
+    assert!(start == end || (end - start).signum() == step.signum());
+
+    let mut len = if start == end {
+        0
     } else {
-        (end - start) / T::cast(n - 1)
+        usize::cast(((end - start) / step).ceil())
     };
 
-    Logspace {
+    // `(end - start) / step` can round up by one element, which would put `start + step * (len -
+    // 1)` at or past `end`; trim it so every element stays strictly inside `[start, end)`
+    if len > 0 {
+        let last = start + step * T::cast(len - 1);
+
+        if (step > _0 && last >= end) || (step < _0 && last <= end) {
+            len -= 1;
+        }
+    }
+
+    Range {
         start: start,
         state: 0,
         step: step,
-        stop: n,
+        stop: len,
     }
 }
@@ -33,7 +33,8 @@ macro_rules! float {
                 mod rev {
                     use quickcheck::TestResult;
 
-                    // Check that `linspace(..).rev()` yields evenly spaced numbers
+                    // Check that `linspace(..).rev()` yields evenly spaced numbers with exact
+                    // endpoints
                     #[quickcheck]
                     fn evenly_spaced(start: $ty, end: $ty, n: usize) -> TestResult {
                         use test::IsClose;
@@ -45,13 +46,18 @@ macro_rules! float {
                         let v = ::linspace(start, end, n).rev().collect::<Vec<_>>();
                         let mut spaces = v.windows(2).map(|w| w[1] - w[0]);
 
-                        test!(match spaces.next() {
+                        let spaced = match spaces.next() {
                             None => true,
                             Some(first) => spaces.all(|space| space.is_close(first))
-                        })
+                        };
+                        let endpoints = v.first().map_or(true, |&f| f == end) &&
+                            v.last().map_or(true, |&l| l == start);
+
+                        test!(spaced && endpoints)
                     }
 
-                    // Check that `linspace(..).rev()` produces a monotonically decreasing sequence
+                    // Check that `linspace(..).rev()` produces a monotonically decreasing
+                    // sequence with exact endpoints
                     #[quickcheck]
                     fn monotonic(start: $ty, end: $ty, n: usize) -> TestResult {
                         enforce! {
@@ -60,7 +66,11 @@ macro_rules! float {
 
                         let v = ::linspace(start, end, n).rev().collect::<Vec<_>>();
 
-                        test!(v.windows(2).all(|w| w[1] <= w[0]))
+                        let monotonic = v.windows(2).all(|w| w[1] <= w[0]);
+                        let endpoints = v.first().map_or(true, |&f| f == end) &&
+                            v.last().map_or(true, |&l| l == start);
+
+                        test!(monotonic && endpoints)
                     }
 
                     // Check that `linspace(_, _, n).rev()` yields exactly `n` numbers
@@ -74,7 +84,7 @@ macro_rules! float {
                     }
                 }
 
-                // Check that `linspace(..)` yields evenly spaced numbers
+                // Check that `linspace(..)` yields evenly spaced numbers with exact endpoints
                 #[quickcheck]
                 fn evenly_spaced(start: $ty, end: $ty, n: usize) -> TestResult {
                     use test::IsClose;
@@ -86,13 +96,18 @@ macro_rules! float {
                     let v = ::linspace(start, end, n).collect::<Vec<_>>();
                     let mut spaces = v.windows(2).map(|w| w[1] - w[0]);
 
-                    test!(match spaces.next() {
+                    let spaced = match spaces.next() {
                         None => true,
                         Some(first) => spaces.all(|space| space.is_close(first))
-                    })
+                    };
+                    let endpoints = v.first().map_or(true, |&f| f == start) &&
+                        v.last().map_or(true, |&l| l == end);
+
+                    test!(spaced && endpoints)
                 }
 
-                // Check that `linspace(..)` produces a monotonic increasing sequence
+                // Check that `linspace(..)` produces a monotonic increasing sequence with exact
+                // endpoints
                 #[quickcheck]
                 fn monotonic(start: $ty, end: $ty, n: usize) -> TestResult {
                     enforce! {
@@ -101,7 +116,11 @@ macro_rules! float {
 
                     let v = ::linspace(start, end, n).collect::<Vec<_>>();
 
-                    test!(v.windows(2).all(|w| w[1] >= w[0]))
+                    let monotonic = v.windows(2).all(|w| w[1] >= w[0]);
+                    let endpoints = v.first().map_or(true, |&f| f == start) &&
+                        v.last().map_or(true, |&l| l == end);
+
+                    test!(monotonic && endpoints)
                 }
 
                 // Check that `linspace(_, _, n)` yields exactly `n` numbers
@@ -113,6 +132,42 @@ macro_rules! float {
 
                     test!(::linspace(start, end, n).count() == n)
                 }
+
+                // Check that `ExactSizeIterator::len` matches the number of elements yielded
+                #[quickcheck]
+                fn len(start: $ty, end: $ty, n: usize) -> TestResult {
+                    enforce! {
+                        start <= end,
+                    }
+
+                    let it = ::linspace(start, end, n);
+                    let len = it.len();
+
+                    test!(it.count() == len)
+                }
+
+                // Check that `nth` jumps directly to the value reached by stepping one at a time
+                #[quickcheck]
+                fn nth(start: $ty, end: $ty, n: usize, skip: usize) -> TestResult {
+                    enforce! {
+                        start <= end,
+                    }
+
+                    test!(::linspace(start, end, n).skip(skip).next() ==
+                        ::linspace(start, end, n).nth(skip))
+                }
+
+                // Check that `nth_back` jumps directly to the value reached by stepping
+                // backward one at a time
+                #[quickcheck]
+                fn nth_back(start: $ty, end: $ty, n: usize, skip: usize) -> TestResult {
+                    enforce! {
+                        start <= end,
+                    }
+
+                    test!(::linspace(start, end, n).rev().skip(skip).next() ==
+                        ::linspace(start, end, n).nth_back(skip))
+                }
             }
 
             mod logspace {
@@ -121,7 +176,8 @@ macro_rules! float {
                 mod rev {
                     use quickcheck::TestResult;
 
-                    // Check that `logspace(..).rev()` yields evenly spaced numbers
+                    // Check that `logspace(..).rev()` yields evenly spaced numbers with exact
+                    // endpoints
                     #[quickcheck]
                     fn evenly_spaced(start: $ty, end: $ty, n: usize) -> TestResult {
                         use test::IsClose;
@@ -136,13 +192,18 @@ macro_rules! float {
                             w[1].ln() - w[0].ln()
                         });
 
-                        test!(match spaces.next() {
+                        let spaced = match spaces.next() {
                             None => true,
                             Some(first) => spaces.all(|space| space.is_close(first))
-                        })
+                        };
+                        let endpoints = v.first().map_or(true, |&f| f == end) &&
+                            v.last().map_or(true, |&l| l == start);
+
+                        test!(spaced && endpoints)
                     }
 
-                    // Check that `logspace(..).rev()` produces a monotonically decreasing sequence
+                    // Check that `logspace(..).rev()` produces a monotonically decreasing
+                    // sequence with exact endpoints
                     #[quickcheck]
                     fn monotonic(start: $ty, end: $ty, n: usize) -> TestResult {
                         enforce! {
@@ -152,8 +213,11 @@ macro_rules! float {
 
                         let v = ::logspace(start, end, n).rev().collect::<Vec<_>>();
 
-                        test!(v.windows(2).all(|w| w[1] <= w[0]))
+                        let monotonic = v.windows(2).all(|w| w[1] <= w[0]);
+                        let endpoints = v.first().map_or(true, |&f| f == end) &&
+                            v.last().map_or(true, |&l| l == start);
 
+                        test!(monotonic && endpoints)
                     }
 
                     // Check that `logspace(_, _, n).rev()` yields exactly `n` numbers
@@ -168,7 +232,7 @@ macro_rules! float {
                     }
                 }
 
-                // Check that `logspace(..)` yields evenly spaced numbers
+                // Check that `logspace(..)` yields evenly spaced numbers with exact endpoints
                 #[quickcheck]
                 fn evenly_spaced(start: $ty, end: $ty, n: usize) -> TestResult {
                     use test::IsClose;
@@ -183,13 +247,18 @@ macro_rules! float {
                         w[1].ln() - w[0].ln()
                     });
 
-                    test!(match spaces.next() {
+                    let spaced = match spaces.next() {
                         None => true,
                         Some(first) => spaces.all(|space| space.is_close(first))
-                    })
+                    };
+                    let endpoints = v.first().map_or(true, |&f| f == start) &&
+                        v.last().map_or(true, |&l| l == end);
+
+                    test!(spaced && endpoints)
                 }
 
-                // Check that `logspace(..)` produces a monotonically increasing sequence
+                // Check that `logspace(..)` produces a monotonically increasing sequence with
+                // exact endpoints
                 #[quickcheck]
                 fn monotonic(start: $ty, end: $ty, n: usize) -> TestResult {
                     enforce! {
@@ -199,7 +268,11 @@ macro_rules! float {
 
                     let v = ::logspace(start, end, n).collect::<Vec<_>>();
 
-                    test!(v.windows(2).all(|w| w[1] >= w[0]))
+                    let monotonic = v.windows(2).all(|w| w[1] >= w[0]);
+                    let endpoints = v.first().map_or(true, |&f| f == start) &&
+                        v.last().map_or(true, |&l| l == end);
+
+                    test!(monotonic && endpoints)
                 }
 
                 // Check that `logspace(_, _, n)` yields exactly `n` numbers
@@ -212,6 +285,458 @@ macro_rules! float {
 
                     test!(::logspace(start, end, n).count() == n)
                 }
+
+                // Check that `ExactSizeIterator::len` matches the number of elements yielded
+                #[quickcheck]
+                fn len(start: $ty, end: $ty, n: usize) -> TestResult {
+                    enforce! {
+                        start > 0.,
+                        start <= end,
+                    }
+
+                    let it = ::logspace(start, end, n);
+                    let len = it.len();
+
+                    test!(it.count() == len)
+                }
+
+                // Check that `nth` jumps directly to the value reached by stepping one at a time
+                #[quickcheck]
+                fn nth(start: $ty, end: $ty, n: usize, skip: usize) -> TestResult {
+                    enforce! {
+                        start > 0.,
+                        start <= end,
+                    }
+
+                    test!(::logspace(start, end, n).skip(skip).next() ==
+                        ::logspace(start, end, n).nth(skip))
+                }
+
+                // Check that `nth_back` jumps directly to the value reached by stepping
+                // backward one at a time
+                #[quickcheck]
+                fn nth_back(start: $ty, end: $ty, n: usize, skip: usize) -> TestResult {
+                    enforce! {
+                        start > 0.,
+                        start <= end,
+                    }
+
+                    test!(::logspace(start, end, n).rev().skip(skip).next() ==
+                        ::logspace(start, end, n).nth_back(skip))
+                }
+            }
+            mod geomspace {
+                use quickcheck::TestResult;
+
+                mod rev {
+                    use quickcheck::TestResult;
+
+                    // Check that `geomspace(..).rev()` yields a constant ratio between
+                    // consecutive elements, with exact endpoints
+                    #[quickcheck]
+                    fn evenly_spaced(start: $ty, end: $ty, n: usize) -> TestResult {
+                        use test::IsClose;
+
+                        enforce! {
+                            start != 0.,
+                            end != 0.,
+                            start.signum() == end.signum(),
+                            start <= end,
+                        }
+
+                        let v = ::geomspace(start, end, n).rev().collect::<Vec<_>>();
+                        let mut ratios = v.windows(2).map(|w| w[1] / w[0]);
+
+                        let spaced = match ratios.next() {
+                            None => true,
+                            Some(first) => ratios.all(|ratio| ratio.is_close(first))
+                        };
+                        let endpoints = v.first().map_or(true, |&f| f == end) &&
+                            v.last().map_or(true, |&l| l == start);
+
+                        test!(spaced && endpoints)
+                    }
+
+                    // Check that `geomspace(..).rev()` produces a monotonically decreasing
+                    // sequence with exact endpoints
+                    #[quickcheck]
+                    fn monotonic(start: $ty, end: $ty, n: usize) -> TestResult {
+                        enforce! {
+                            start != 0.,
+                            end != 0.,
+                            start.signum() == end.signum(),
+                            start <= end,
+                        }
+
+                        let v = ::geomspace(start, end, n).rev().collect::<Vec<_>>();
+
+                        let monotonic = v.windows(2).all(|w| w[1] <= w[0]);
+                        let endpoints = v.first().map_or(true, |&f| f == end) &&
+                            v.last().map_or(true, |&l| l == start);
+
+                        test!(monotonic && endpoints)
+                    }
+
+                    // Check that `geomspace(_, _, n).rev()` yields exactly `n` numbers
+                    #[quickcheck]
+                    fn size(start: $ty, end: $ty, n: usize) -> TestResult {
+                        enforce! {
+                            start != 0.,
+                            end != 0.,
+                            start.signum() == end.signum(),
+                            start <= end,
+                        }
+
+                        test!(::geomspace(start, end, n).rev().count() == n)
+                    }
+                }
+
+                // Check that `geomspace(..)` yields a constant ratio between consecutive
+                // elements, with exact endpoints
+                #[quickcheck]
+                fn evenly_spaced(start: $ty, end: $ty, n: usize) -> TestResult {
+                    use test::IsClose;
+
+                    enforce! {
+                        start != 0.,
+                        end != 0.,
+                        start.signum() == end.signum(),
+                        start <= end,
+                    }
+
+                    let v = ::geomspace(start, end, n).collect::<Vec<_>>();
+                    let mut ratios = v.windows(2).map(|w| w[1] / w[0]);
+
+                    let spaced = match ratios.next() {
+                        None => true,
+                        Some(first) => ratios.all(|ratio| ratio.is_close(first))
+                    };
+                    let endpoints = v.first().map_or(true, |&f| f == start) &&
+                        v.last().map_or(true, |&l| l == end);
+
+                    test!(spaced && endpoints)
+                }
+
+                // Check that `geomspace(..)` produces a monotonically increasing sequence with
+                // exact endpoints
+                #[quickcheck]
+                fn monotonic(start: $ty, end: $ty, n: usize) -> TestResult {
+                    enforce! {
+                        start != 0.,
+                        end != 0.,
+                        start.signum() == end.signum(),
+                        start <= end,
+                    }
+
+                    let v = ::geomspace(start, end, n).collect::<Vec<_>>();
+
+                    let monotonic = v.windows(2).all(|w| w[1] >= w[0]);
+                    let endpoints = v.first().map_or(true, |&f| f == start) &&
+                        v.last().map_or(true, |&l| l == end);
+
+                    test!(monotonic && endpoints)
+                }
+
+                // Check that `geomspace(_, _, n)` yields exactly `n` numbers
+                #[quickcheck]
+                fn size(start: $ty, end: $ty, n: usize) -> TestResult {
+                    enforce! {
+                        start != 0.,
+                        end != 0.,
+                        start.signum() == end.signum(),
+                        start <= end,
+                    }
+
+                    test!(::geomspace(start, end, n).count() == n)
+                }
+
+                // Check that `ExactSizeIterator::len` matches the number of elements yielded
+                #[quickcheck]
+                fn len(start: $ty, end: $ty, n: usize) -> TestResult {
+                    enforce! {
+                        start != 0.,
+                        end != 0.,
+                        start.signum() == end.signum(),
+                        start <= end,
+                    }
+
+                    let it = ::geomspace(start, end, n);
+                    let len = it.len();
+
+                    test!(it.count() == len)
+                }
+
+                // Check that `nth` jumps directly to the value reached by stepping one at a time
+                #[quickcheck]
+                fn nth(start: $ty, end: $ty, n: usize, skip: usize) -> TestResult {
+                    enforce! {
+                        start != 0.,
+                        end != 0.,
+                        start.signum() == end.signum(),
+                        start <= end,
+                    }
+
+                    test!(::geomspace(start, end, n).skip(skip).next() ==
+                        ::geomspace(start, end, n).nth(skip))
+                }
+
+                // Check that `nth_back` jumps directly to the value reached by stepping
+                // backward one at a time
+                #[quickcheck]
+                fn nth_back(start: $ty, end: $ty, n: usize, skip: usize) -> TestResult {
+                    enforce! {
+                        start != 0.,
+                        end != 0.,
+                        start.signum() == end.signum(),
+                        start <= end,
+                    }
+
+                    test!(::geomspace(start, end, n).rev().skip(skip).next() ==
+                        ::geomspace(start, end, n).nth_back(skip))
+                }
+            }
+
+            mod logspace_base {
+                use quickcheck::TestResult;
+
+                mod rev {
+                    use quickcheck::TestResult;
+
+                    // Check that `logspace_base(..).rev()` yields evenly spaced exponents, with
+                    // exact endpoints
+                    #[quickcheck]
+                    fn evenly_spaced(base: $ty, start: $ty, end: $ty, n: usize) -> TestResult {
+                        use test::IsClose;
+
+                        enforce! {
+                            base > 0.,
+                            start <= end,
+                        }
+
+                        let v = ::logspace_base(base, start, end, n).rev().collect::<Vec<_>>();
+                        let mut spaces = v.windows(2).map(|w| (w[1].ln() - w[0].ln()) / base.ln());
+
+                        let spaced = match spaces.next() {
+                            None => true,
+                            Some(first) => spaces.all(|space| space.is_close(first))
+                        };
+                        let endpoints = v.first().map_or(true, |&f| f == base.powf(end)) &&
+                            v.last().map_or(true, |&l| l == base.powf(start));
+
+                        test!(spaced && endpoints)
+                    }
+
+                    // Check that `logspace_base(..).rev()` produces a monotonically decreasing
+                    // sequence (increasing when `base` < 1) with exact endpoints
+                    #[quickcheck]
+                    fn monotonic(base: $ty, start: $ty, end: $ty, n: usize) -> TestResult {
+                        enforce! {
+                            base > 0.,
+                            start <= end,
+                        }
+
+                        let v = ::logspace_base(base, start, end, n).rev().collect::<Vec<_>>();
+
+                        let monotonic = if base >= 1. {
+                            v.windows(2).all(|w| w[1] <= w[0])
+                        } else {
+                            v.windows(2).all(|w| w[1] >= w[0])
+                        };
+                        let endpoints = v.first().map_or(true, |&f| f == base.powf(end)) &&
+                            v.last().map_or(true, |&l| l == base.powf(start));
+
+                        test!(monotonic && endpoints)
+                    }
+
+                    // Check that `logspace_base(_, _, _, n).rev()` yields exactly `n` numbers
+                    #[quickcheck]
+                    fn size(base: $ty, start: $ty, end: $ty, n: usize) -> TestResult {
+                        enforce! {
+                            base > 0.,
+                            start <= end,
+                        }
+
+                        test!(::logspace_base(base, start, end, n).rev().count() == n)
+                    }
+                }
+
+                // Check that `logspace_base(..)` yields evenly spaced exponents, with exact
+                // endpoints
+                #[quickcheck]
+                fn evenly_spaced(base: $ty, start: $ty, end: $ty, n: usize) -> TestResult {
+                    use test::IsClose;
+
+                    enforce! {
+                        base > 0.,
+                        start <= end,
+                    }
+
+                    let v = ::logspace_base(base, start, end, n).collect::<Vec<_>>();
+                    let mut spaces = v.windows(2).map(|w| (w[1].ln() - w[0].ln()) / base.ln());
+
+                    let spaced = match spaces.next() {
+                        None => true,
+                        Some(first) => spaces.all(|space| space.is_close(first))
+                    };
+                    let endpoints = v.first().map_or(true, |&f| f == base.powf(start)) &&
+                        v.last().map_or(true, |&l| l == base.powf(end));
+
+                    test!(spaced && endpoints)
+                }
+
+                // Check that `logspace_base(..)` produces a monotonically increasing sequence
+                // (decreasing when `base` < 1) with exact endpoints
+                #[quickcheck]
+                fn monotonic(base: $ty, start: $ty, end: $ty, n: usize) -> TestResult {
+                    enforce! {
+                        base > 0.,
+                        start <= end,
+                    }
+
+                    let v = ::logspace_base(base, start, end, n).collect::<Vec<_>>();
+
+                    let monotonic = if base >= 1. {
+                        v.windows(2).all(|w| w[1] >= w[0])
+                    } else {
+                        v.windows(2).all(|w| w[1] <= w[0])
+                    };
+                    let endpoints = v.first().map_or(true, |&f| f == base.powf(start)) &&
+                        v.last().map_or(true, |&l| l == base.powf(end));
+
+                    test!(monotonic && endpoints)
+                }
+
+                // Check that `logspace_base(_, _, _, n)` yields exactly `n` numbers
+                #[quickcheck]
+                fn size(base: $ty, start: $ty, end: $ty, n: usize) -> TestResult {
+                    enforce! {
+                        base > 0.,
+                        start <= end,
+                    }
+
+                    test!(::logspace_base(base, start, end, n).count() == n)
+                }
+
+                // Check that `ExactSizeIterator::len` matches the number of elements yielded
+                #[quickcheck]
+                fn len(base: $ty, start: $ty, end: $ty, n: usize) -> TestResult {
+                    enforce! {
+                        base > 0.,
+                        start <= end,
+                    }
+
+                    let it = ::logspace_base(base, start, end, n);
+                    let len = it.len();
+
+                    test!(it.count() == len)
+                }
+
+                // Check that `nth` jumps directly to the value reached by stepping one at a time
+                #[quickcheck]
+                fn nth(base: $ty, start: $ty, end: $ty, n: usize, skip: usize) -> TestResult {
+                    enforce! {
+                        base > 0.,
+                        start <= end,
+                    }
+
+                    test!(::logspace_base(base, start, end, n).skip(skip).next() ==
+                        ::logspace_base(base, start, end, n).nth(skip))
+                }
+
+                // Check that `nth_back` jumps directly to the value reached by stepping
+                // backward one at a time
+                #[quickcheck]
+                fn nth_back(base: $ty, start: $ty, end: $ty, n: usize, skip: usize) -> TestResult {
+                    enforce! {
+                        base > 0.,
+                        start <= end,
+                    }
+
+                    test!(::logspace_base(base, start, end, n).rev().skip(skip).next() ==
+                        ::logspace_base(base, start, end, n).nth_back(skip))
+                }
+            }
+
+            mod range {
+                use quickcheck::TestResult;
+
+                mod rev {
+                    use quickcheck::TestResult;
+
+                    // Check that `range(..).rev()` yields numbers separated by `step`
+                    #[quickcheck]
+                    fn evenly_spaced(start: $ty, end: $ty, step: $ty) -> TestResult {
+                        use test::IsClose;
+
+                        enforce! {
+                            step != 0.,
+                            start == end || (end - start).signum() == step.signum(),
+                        }
+
+                        let v = ::range(start, end, step).rev().collect::<Vec<_>>();
+
+                        test!(v.windows(2).all(|w| (w[0] - w[1]).is_close(step)))
+                    }
+
+                    // Check that `range(_, _, step).rev()` yields the same number of elements
+                    // as `range(_, _, step)`
+                    #[quickcheck]
+                    fn size(start: $ty, end: $ty, step: $ty) -> TestResult {
+                        enforce! {
+                            step != 0.,
+                            start == end || (end - start).signum() == step.signum(),
+                        }
+
+                        test!(::range(start, end, step).rev().count() ==
+                            ::range(start, end, step).len())
+                    }
+                }
+
+                // Check that `range(..)` yields numbers separated by `step`
+                #[quickcheck]
+                fn evenly_spaced(start: $ty, end: $ty, step: $ty) -> TestResult {
+                    use test::IsClose;
+
+                    enforce! {
+                        step != 0.,
+                        start == end || (end - start).signum() == step.signum(),
+                    }
+
+                    let v = ::range(start, end, step).collect::<Vec<_>>();
+
+                    test!(v.windows(2).all(|w| (w[1] - w[0]).is_close(step)))
+                }
+
+                // Check that `range(..)` never yields `end` or anything past it
+                #[quickcheck]
+                fn exclusive(start: $ty, end: $ty, step: $ty) -> TestResult {
+                    enforce! {
+                        step != 0.,
+                        start == end || (end - start).signum() == step.signum(),
+                    }
+
+                    let v = ::range(start, end, step).collect::<Vec<_>>();
+
+                    test!(if step > 0. {
+                        v.iter().all(|&x| x < end)
+                    } else {
+                        v.iter().all(|&x| x > end)
+                    })
+                }
+
+                // Check that `range(_, _, step).len()` matches the number of elements yielded
+                #[quickcheck]
+                fn size(start: $ty, end: $ty, step: $ty) -> TestResult {
+                    enforce! {
+                        step != 0.,
+                        start == end || (end - start).signum() == step.signum(),
+                    }
+
+                    let r = ::range(start, end, step);
+                    let len = r.len();
+
+                    test!(r.count() == len)
+                }
             }
         })+
     }